@@ -0,0 +1,91 @@
+// Benchmarking setup for pallet-kitties
+use super::*;
+use crate::Pallet as Kitties;
+use frame::benchmarking::prelude::*;
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn create_kitty() {
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		create_kitty(RawOrigin::Signed(caller.clone()));
+
+		assert_eq!(CountForKitties::<T>::get(), 1);
+	}
+
+	#[benchmark]
+	fn transfer() {
+		let caller: T::AccountId = whitelisted_caller();
+		let recipient: T::AccountId = account("recipient", 0, 0);
+
+		// Fill the recipient's owned list close to the MaxKittiesOwned bound so the
+		// `try_push`/`swap_remove` inside `do_transfer` pays its worst-case cost
+		for _ in 0..T::MaxKittiesOwned::get() - 1 {
+			Kitties::<T>::create_kitty(RawOrigin::Signed(recipient.clone()).into())?;
+		}
+
+		Kitties::<T>::create_kitty(RawOrigin::Signed(caller.clone()).into())?;
+		let kitty_id = KittiesOwned::<T>::get(&caller)[0];
+
+		#[extrinsic_call]
+		transfer(RawOrigin::Signed(caller), recipient.clone(), kitty_id);
+
+		assert_eq!(Kitties::<T>::get(kitty_id).unwrap().owner, recipient);
+	}
+
+	#[benchmark]
+	fn set_price() {
+		let caller: T::AccountId = whitelisted_caller();
+		Kitties::<T>::create_kitty(RawOrigin::Signed(caller.clone()).into())?;
+		let kitty_id = KittiesOwned::<T>::get(&caller)[0];
+
+		#[extrinsic_call]
+		set_price(RawOrigin::Signed(caller), kitty_id, Some(100u32.into()));
+
+		assert_eq!(Kitties::<T>::get(kitty_id).unwrap().price, Some(100u32.into()));
+	}
+
+	#[benchmark]
+	fn buy_kitty() {
+		let seller: T::AccountId = account("seller", 0, 0);
+		let buyer: T::AccountId = whitelisted_caller();
+		let price: BalanceOf<T> = 100u32.into();
+
+		T::NativeBalance::mint_into(&buyer, price * 2u32.into())?;
+
+		Kitties::<T>::create_kitty(RawOrigin::Signed(seller.clone()).into())?;
+		let kitty_id = KittiesOwned::<T>::get(&seller)[0];
+		Kitties::<T>::set_price(RawOrigin::Signed(seller.clone()).into(), kitty_id, Some(price))?;
+
+		// Fill the buyer's owned list close to the MaxKittiesOwned bound so the
+		// `try_push`/`swap_remove` inside `do_buy_kitty` pays its worst-case cost
+		for _ in 0..T::MaxKittiesOwned::get() - 1 {
+			Kitties::<T>::create_kitty(RawOrigin::Signed(buyer.clone()).into())?;
+		}
+
+		#[extrinsic_call]
+		buy_kitty(RawOrigin::Signed(buyer.clone()), kitty_id, price);
+
+		assert_eq!(Kitties::<T>::get(kitty_id).unwrap().owner, buyer);
+	}
+
+	#[benchmark]
+	fn breed_kitty() {
+		let caller: T::AccountId = whitelisted_caller();
+		Kitties::<T>::create_kitty(RawOrigin::Signed(caller.clone()).into())?;
+		Kitties::<T>::create_kitty(RawOrigin::Signed(caller.clone()).into())?;
+		let owned = KittiesOwned::<T>::get(&caller);
+		let (parent1, parent2) = (owned[0], owned[1]);
+
+		#[extrinsic_call]
+		breed_kitty(RawOrigin::Signed(caller), parent1, parent2);
+
+		assert_eq!(CountForKitties::<T>::get(), 3);
+	}
+
+	impl_benchmark_test_suite!(Kitties, crate::tests::new_test_ext(vec![]), crate::tests::Test);
+}
@@ -11,12 +11,20 @@ use frame::traits::Hash;
 impl<T: Config> Pallet<T> {
 	// Function to generate a unique DNA hash for a kitty
 	pub fn gen_dna() -> [u8; 32] {
+		// Current kitty count, used both as the randomness subject and as extra entropy
+		let subject = CountForKitties::<T>::get();
+
+		// Pull a fresh seed from the configured randomness source, keyed on the subject so
+		// multiple kitties minted in the same block still get distinct randomness
+		let (random_seed, _) = T::Randomness::random(&subject.encode());
+
 		// Create a tuple of unique values from the current blockchain state
 		let unique_payload = (
 			frame_system::Pallet::<T>::parent_hash(),        // Hash of the parent block
 			frame_system::Pallet::<T>::block_number(),       // Current block number
 			frame_system::Pallet::<T>::extrinsic_index(),    // Current extrinsic index (in block)
-			CountForKitties::<T>::get(),                     // Current count of kitties created
+			subject,                                         // Current count of kitties created
+			random_seed,                                     // Fresh on-chain randomness
 		);
 
 		// Hash the payload using BlakeTwo256 and convert to [u8; 32] array
@@ -24,9 +32,9 @@ impl<T: Config> Pallet<T> {
 	}
 
 	// Function to mint (create) a new kitty and store it on chain
-	pub fn mint(owner: T::AccountId, dna: [u8; 32]) -> DispatchResult {
-		// Create the Kitty struct with DNA, owner, and no price initially
-		let kitty = Kitty { dna, owner: owner.clone(), price: None };
+	pub fn mint(owner: T::AccountId, dna: [u8; 32], generation: u16) -> DispatchResult {
+		// Create the Kitty struct with DNA, owner, no price initially, and the given generation
+		let kitty = Kitty { dna, owner: owner.clone(), price: None, generation };
 
 		// Ensure this DNA does not already exist (no duplicate kitties)
 		ensure!(!Kitties::<T>::contains_key(dna), Error::<T>::DuplicateKitty);
@@ -37,7 +45,7 @@ impl<T: Config> Pallet<T> {
 		// Try to increment it, fail with error if overflow
 		let new_count = current_count.checked_add(1).ok_or(Error::<T>::TooManyKitties)?;
 
-		// Try to add kitty DNA to the owner's owned list (bounded vec max 100)
+		// Try to add kitty DNA to the owner's owned list (bounded by MaxKittiesOwned)
 		KittiesOwned::<T>::try_append(&owner, dna).map_err(|_| Error::<T>::TooManyOwned)?;
 
 		// Store the new kitty and update the counter
@@ -111,25 +119,79 @@ impl<T: Config> Pallet<T> {
 	pub fn do_buy_kitty(
 		buyer: T::AccountId,
 		kitty_id: [u8; 32],
-		price: BalanceOf<T>,
+		max_price: BalanceOf<T>,
 	) -> DispatchResult {
 		// Fetch the kitty or fail if doesn't exist
-		let kitty = Kitties::<T>::get(kitty_id).ok_or(Error::<T>::NoKitty)?;
+		let mut kitty = Kitties::<T>::get(kitty_id).ok_or(Error::<T>::NoKitty)?;
+		let seller = kitty.owner.clone();
 
 		// Ensure the kitty is for sale
 		let real_price = kitty.price.ok_or(Error::<T>::NotForSale)?;
 
 		// Buyer must offer equal or higher than listed price
-		ensure!(price >= real_price, Error::<T>::MaxPriceTooLow);
-
-		// Transfer funds from buyer to current owner
-		T::NativeBalance::transfer(&buyer, &kitty.owner, real_price, Preservation::Preserve)?;
+		ensure!(max_price >= real_price, Error::<T>::MaxPriceTooLow);
+
+		// Run every check that do_transfer would run *before* any funds move, so a failed
+		// purchase never leaves the buyer's balance debited without the kitty changing hands
+		ensure!(seller != buyer, Error::<T>::TransferToSelf);
+		let mut buyer_owned = KittiesOwned::<T>::get(&buyer);
+		buyer_owned.try_push(kitty_id).map_err(|_| Error::<T>::TooManyOwned)?;
+		let mut seller_owned = KittiesOwned::<T>::get(&seller);
+		let seller_index =
+			seller_owned.iter().position(|&id| id == kitty_id).ok_or(Error::<T>::NoKitty)?;
+
+		// Every fallible check above has passed, so it is now safe to move funds
+		T::NativeBalance::transfer(&buyer, &seller, real_price, Preservation::Preserve)?;
+
+		// The remaining storage writes cannot fail: the index was already found above
+		seller_owned.swap_remove(seller_index);
+		kitty.owner = buyer.clone();
+		kitty.price = None;
 
-		// Transfer kitty ownership
-		Self::do_transfer(kitty.owner, buyer.clone(), kitty_id)?;
+		Kitties::<T>::insert(kitty_id, kitty);
+		KittiesOwned::<T>::insert(&buyer, buyer_owned);
+		KittiesOwned::<T>::insert(&seller, seller_owned);
 
-		// Emit Sold event
+		// Emit transfer and sold events
+		Self::deposit_event(Event::<T>::Transferred { from: seller, to: buyer.clone(), kitty_id });
 		Self::deposit_event(Event::<T>::Sold { buyer, kitty_id, price: real_price });
 		Ok(())
 	}
+
+	// Logic to breed two owned kitties into a new offspring
+	pub fn do_breed(caller: T::AccountId, parent1: [u8; 32], parent2: [u8; 32]) -> DispatchResult {
+		// Parents must be different kitties
+		ensure!(parent1 != parent2, Error::<T>::SameParents);
+
+		// Fetch both parents, or fail if either doesn't exist
+		let kitty1 = Kitties::<T>::get(parent1).ok_or(Error::<T>::NoKitty)?;
+		let kitty2 = Kitties::<T>::get(parent2).ok_or(Error::<T>::NoKitty)?;
+
+		// Caller must own both parents
+		ensure!(kitty1.owner == caller, Error::<T>::NotOwner);
+		ensure!(kitty2.owner == caller, Error::<T>::NotOwner);
+
+		// Mix parent DNA byte by byte, picking the parent for each byte using a random selector
+		let selector = Self::gen_dna();
+		let mut child_dna = [0u8; 32];
+		for (i, out) in child_dna.iter_mut().enumerate() {
+			*out = if selector[i] & 1 == 0 { kitty1.dna[i] } else { kitty2.dna[i] };
+		}
+
+		// Hash the mixed DNA together with the current kitty count to guarantee uniqueness
+		let unique_payload = (child_dna, CountForKitties::<T>::get());
+		let child: [u8; 32] = BlakeTwo256::hash_of(&unique_payload).into();
+
+		// A bred kitty's generation is one more than the higher of its parents' generations
+		let generation = kitty1
+			.generation
+			.max(kitty2.generation)
+			.checked_add(1)
+			.ok_or(Error::<T>::GenerationOverflow)?;
+
+		// Mint the child kitty and record its lineage
+		Self::mint(caller.clone(), child, generation)?;
+		Self::deposit_event(Event::<T>::Bred { owner: caller, parent1, parent2, child });
+		Ok(())
+	}
 }
\ No newline at end of file
@@ -3,7 +3,13 @@
 
 // Include other Rust modules in this pallet
 mod impls; // Contains the main business logic for the pallet
+#[cfg(test)]
 mod tests; // Unit tests for this pallet
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking; // Benchmarks used to calculate extrinsic weights
+pub mod weights; // WeightInfo trait and placeholder weight implementations
+
+pub use weights::WeightInfo;
 
 // Import useful Substrate macros and types
 use frame::prelude::*;
@@ -12,11 +18,14 @@ use frame::prelude::*;
 use frame::traits::fungible::Inspect;
 use frame::traits::fungible::Mutate;
 
+// Import the Randomness trait used to source on-chain randomness for DNA generation
+use frame::traits::Randomness;
+
 // Make the pallet module available to external users
 pub use pallet::*;
 
 // Define the actual pallet
-#[frame::pallet(dev_mode)] // Dev mode enables extra logging and debug features
+#[frame::pallet]
 pub mod pallet {
 	use super::*; // Bring external definitions into scope
 
@@ -32,6 +41,16 @@ pub mod pallet {
 
 		// Native token used for balance transfers and pricing
 		type NativeBalance: Inspect<Self::AccountId> + Mutate<Self::AccountId>;
+
+		// Source of on-chain randomness used to generate unpredictable kitty DNA
+		type Randomness: Randomness<Self::Hash, BlockNumberFor<Self>>;
+
+		// Weight information for this pallet's extrinsics
+		type WeightInfo: WeightInfo;
+
+		// Maximum number of kitties a single account may own at once
+		#[pallet::constant]
+		type MaxKittiesOwned: Get<u32>;
 	}
 
 	// Shortcut type to get the balance type for this runtime
@@ -45,6 +64,7 @@ pub mod pallet {
 		pub dna: [u8; 32],                 // Unique identifier for the kitty
 		pub owner: T::AccountId,          // Account that owns this kitty
 		pub price: Option<BalanceOf<T>>,  // Optional price if the kitty is listed for sale
+		pub generation: u16,              // 0 for freshly created kitties, max(parents) + 1 for bred ones
 	}
 
 	// Simple counter to keep track of how many kitties have been created
@@ -55,11 +75,11 @@ pub mod pallet {
 	#[pallet::storage]
 	pub(super) type Kitties<T: Config> = StorageMap<Key = [u8; 32], Value = Kitty<T>>;
 
-	// Maps each user (AccountId) to a list of kitties they own (bounded to 100)
+	// Maps each user (AccountId) to a list of kitties they own (bounded by MaxKittiesOwned)
 	#[pallet::storage]
 	pub(super) type KittiesOwned<T: Config> = StorageMap<
 		Key = T::AccountId,
-		Value = BoundedVec<[u8; 32], ConstU32<100>>, // Up to 100 kitties per account
+		Value = BoundedVec<[u8; 32], T::MaxKittiesOwned>, // Up to MaxKittiesOwned kitties per account
 		QueryKind = ValueQuery,
 	>;
 
@@ -71,6 +91,7 @@ pub mod pallet {
 		Transferred { from: T::AccountId, to: T::AccountId, kitty_id: [u8; 32] }, // A kitty was transferred
 		PriceSet { owner: T::AccountId, kitty_id: [u8; 32], new_price: Option<BalanceOf<T>> }, // Price was updated
 		Sold { buyer: T::AccountId, kitty_id: [u8; 32], price: BalanceOf<T> }, // Kitty was bought
+		Bred { owner: T::AccountId, parent1: [u8; 32], parent2: [u8; 32], child: [u8; 32] }, // A new kitty was bred from two parents
 	}
 
 	// Define possible errors that can occur in pallet operations
@@ -78,26 +99,32 @@ pub mod pallet {
 	pub enum Error<T> {
 		TooManyKitties,       // Global limit reached for total kitties
 		DuplicateKitty,       // A kitty with same DNA already exists
-		TooManyOwned,         // User owns too many kitties (over 100)
+		TooManyOwned,         // User owns too many kitties (over MaxKittiesOwned)
 		TransferToSelf,       // Cannot transfer a kitty to yourself
 		NoKitty,              // Kitty not found
 		NotOwner,             // Caller does not own the kitty
 		NotForSale,           // Kitty is not listed for sale
 		MaxPriceTooLow,       // Offered price is less than sale price
+		SameParents,          // Cannot breed a kitty with itself
+		GenerationOverflow,   // Bred kitty's generation would overflow u16
 	}
 
 	// Define the callable (extrinsic) functions available to the blockchain
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		// Public function to create a new kitty
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::create_kitty())]
 		pub fn create_kitty(origin: OriginFor<T>) -> DispatchResult {
 			let who = ensure_signed(origin)?; // Ensure transaction is signed
 			let dna = Self::gen_dna();        // Generate unique DNA
-			Self::mint(who, dna)?;            // Mint the kitty and store it
+			Self::mint(who, dna, 0)?;         // Mint the kitty and store it (generation 0)
 			Ok(())
 		}
 
 		// Public function to transfer a kitty to another user
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::transfer())]
 		pub fn transfer(
 			origin: OriginFor<T>,
 			to: T::AccountId,
@@ -109,6 +136,8 @@ pub mod pallet {
 		}
 
 		// Public function to set or unset the price of a kitty
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::set_price())]
 		pub fn set_price(
 			origin: OriginFor<T>,
 			kitty_id: [u8; 32],
@@ -120,6 +149,8 @@ pub mod pallet {
 		}
 
 		// Public function to buy a kitty if it's listed for sale
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::buy_kitty())]
 		pub fn buy_kitty(
 			origin: OriginFor<T>,
 			kitty_id: [u8; 32],
@@ -129,5 +160,18 @@ pub mod pallet {
 			Self::do_buy_kitty(who, kitty_id, max_price)?;     // Handle internal buy logic
 			Ok(())
 		}
+
+		// Public function to breed two owned kitties into a new offspring
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::breed_kitty())]
+		pub fn breed_kitty(
+			origin: OriginFor<T>,
+			parent1: [u8; 32],
+			parent2: [u8; 32],
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;                  // Ensure caller is signed
+			Self::do_breed(who, parent1, parent2)?;            // Handle internal breeding logic
+			Ok(())
+		}
 	}
 }
@@ -0,0 +1,164 @@
+// Mock runtime and regression tests for pallet-kitties
+use crate as pallet_kitties;
+use crate::{CountForKitties, Error, Kitties, KittiesOwned};
+use frame::testing_prelude::*;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+// Build a minimal runtime with just the system, balances, and kitties pallets
+construct_runtime!(
+	pub struct Test {
+		System: frame_system,
+		Balances: pallet_balances,
+		PalletKitties: pallet_kitties,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+	type Block = Block;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+	type AccountStore = System;
+}
+
+// Deterministic stand-in for on-chain randomness; real runtimes wire in something unpredictable
+pub struct MockRandomness;
+impl Randomness<<Test as frame_system::Config>::Hash, BlockNumberFor<Test>> for MockRandomness {
+	fn random(subject: &[u8]) -> (<Test as frame_system::Config>::Hash, BlockNumberFor<Test>) {
+		(BlakeTwo256::hash_of(&(subject, System::block_number())), System::block_number())
+	}
+}
+
+impl pallet_kitties::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type NativeBalance = Balances;
+	type Randomness = MockRandomness;
+	type WeightInfo = ();
+	type MaxKittiesOwned = ConstU32<100>;
+}
+
+const ALICE: u64 = 1;
+const BOB: u64 = 2;
+
+// Build genesis storage crediting `balances` to the listed accounts
+pub(crate) fn new_test_ext(balances: Vec<(u64, u64)>) -> TestState {
+	let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	pallet_balances::GenesisConfig::<Test> { balances, ..Default::default() }
+		.assimilate_storage(&mut t)
+		.unwrap();
+	t.into()
+}
+
+#[test]
+fn buy_kitty_fails_when_buyer_owned_is_full_and_leaves_balances_untouched() {
+	new_test_ext(vec![(ALICE, 1_000), (BOB, 1_000)]).execute_with(|| {
+		// Alice creates and lists one kitty for sale
+		assert_ok!(PalletKitties::create_kitty(RuntimeOrigin::signed(ALICE)));
+		let kitty_id = Kitties::<Test>::iter_keys().next().unwrap();
+		assert_ok!(PalletKitties::set_price(RuntimeOrigin::signed(ALICE), kitty_id, Some(100)));
+
+		// Fill Bob's owned list up to the 100-kitty cap
+		for _ in 0..100 {
+			assert_ok!(PalletKitties::create_kitty(RuntimeOrigin::signed(BOB)));
+		}
+
+		let alice_before = Balances::free_balance(ALICE);
+		let bob_before = Balances::free_balance(BOB);
+
+		// Bob is already at capacity, so the purchase must fail before any funds move
+		assert_noop!(
+			PalletKitties::buy_kitty(RuntimeOrigin::signed(BOB), kitty_id, 100),
+			Error::<Test>::TooManyOwned,
+		);
+
+		assert_eq!(Balances::free_balance(ALICE), alice_before);
+		assert_eq!(Balances::free_balance(BOB), bob_before);
+	});
+}
+
+#[test]
+fn breed_kitty_mixes_dna_and_bumps_generation() {
+	new_test_ext(vec![(ALICE, 1_000)]).execute_with(|| {
+		// frame_system records no events at block 0, so move to block 1 before checking events
+		System::set_block_number(1);
+		assert_ok!(PalletKitties::create_kitty(RuntimeOrigin::signed(ALICE)));
+		assert_ok!(PalletKitties::create_kitty(RuntimeOrigin::signed(ALICE)));
+		let owned = KittiesOwned::<Test>::get(&ALICE);
+		let (parent1, parent2) = (owned[0], owned[1]);
+		let kitty1 = Kitties::<Test>::get(parent1).unwrap();
+		let kitty2 = Kitties::<Test>::get(parent2).unwrap();
+
+		// Nothing mutates the chain state that feeds gen_dna() between this call and the
+		// one `do_breed` makes internally, so both draw the exact same selector
+		let selector = PalletKitties::gen_dna();
+		let mut expected_dna = [0u8; 32];
+		for i in 0..32 {
+			expected_dna[i] = if selector[i] & 1 == 0 { kitty1.dna[i] } else { kitty2.dna[i] };
+		}
+		let expected_child: [u8; 32] =
+			BlakeTwo256::hash_of(&(expected_dna, CountForKitties::<Test>::get())).into();
+
+		assert_ok!(PalletKitties::breed_kitty(RuntimeOrigin::signed(ALICE), parent1, parent2));
+
+		let child = Kitties::<Test>::get(expected_child)
+			.expect("child kitty should be stored at the predicted id");
+		assert_eq!(child.owner, ALICE);
+		assert_eq!(child.generation, 1);
+		System::assert_last_event(
+			pallet_kitties::Event::Bred { owner: ALICE, parent1, parent2, child: expected_child }
+				.into(),
+		);
+	});
+}
+
+#[test]
+fn breed_kitty_rejects_identical_parents() {
+	new_test_ext(vec![(ALICE, 1_000)]).execute_with(|| {
+		assert_ok!(PalletKitties::create_kitty(RuntimeOrigin::signed(ALICE)));
+		let kitty_id = KittiesOwned::<Test>::get(&ALICE)[0];
+
+		assert_noop!(
+			PalletKitties::breed_kitty(RuntimeOrigin::signed(ALICE), kitty_id, kitty_id),
+			Error::<Test>::SameParents,
+		);
+	});
+}
+
+#[test]
+fn breed_kitty_requires_caller_to_own_both_parents() {
+	new_test_ext(vec![(ALICE, 1_000), (BOB, 1_000)]).execute_with(|| {
+		assert_ok!(PalletKitties::create_kitty(RuntimeOrigin::signed(ALICE)));
+		let alice_kitty = KittiesOwned::<Test>::get(&ALICE)[0];
+		assert_ok!(PalletKitties::create_kitty(RuntimeOrigin::signed(BOB)));
+		let bob_kitty = KittiesOwned::<Test>::get(&BOB)[0];
+
+		// Caller does not own parent2
+		assert_noop!(
+			PalletKitties::breed_kitty(RuntimeOrigin::signed(ALICE), alice_kitty, bob_kitty),
+			Error::<Test>::NotOwner,
+		);
+
+		// Caller does not own parent1
+		assert_noop!(
+			PalletKitties::breed_kitty(RuntimeOrigin::signed(ALICE), bob_kitty, alice_kitty),
+			Error::<Test>::NotOwner,
+		);
+	});
+}
+
+#[test]
+fn breed_kitty_fails_when_a_parent_does_not_exist() {
+	new_test_ext(vec![(ALICE, 1_000)]).execute_with(|| {
+		assert_ok!(PalletKitties::create_kitty(RuntimeOrigin::signed(ALICE)));
+		let real_kitty = KittiesOwned::<Test>::get(&ALICE)[0];
+		let missing_kitty = [0xffu8; 32];
+
+		assert_noop!(
+			PalletKitties::breed_kitty(RuntimeOrigin::signed(ALICE), real_kitty, missing_kitty),
+			Error::<Test>::NoKitty,
+		);
+	});
+}